@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+use crate::math::Vec3;
+use crate::world::{Color, Transform, World};
+
+/// A pinhole camera: a position/orientation plus the lens parameters needed to turn a pixel
+/// offset into a ray, and the flycam's flight characteristics.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Camera {
+    pub transform: Transform,
+    pub px_per_unit: f32,
+    pub focal_length: f32,
+
+    /// Current world-space velocity, in units/second. Not meant to be set from the scene file;
+    /// flight always starts at rest.
+    #[serde(default)]
+    pub velocity: Vec3,
+    /// Radians of rotation per pixel of relative mouse motion.
+    pub turn_sensitivity: f32,
+    /// Acceleration applied while a movement key is held, in units/second^2.
+    pub thrust_mag: f32,
+    /// Half-life of the velocity decay, in seconds: how long it takes drift to fall to half speed.
+    pub half_life: f32,
+}
+
+impl Camera {
+    /// Casts a ray through the pixel at `(x_w, y_w)` (measured from the image center, in pixels)
+    /// and returns the color it sees. `px_per_unit` scales both axes identically, so resizing the
+    /// window widens or narrows the field of view without distorting non-square images.
+    pub fn get_px(&self, world: &World, x_w: f32, y_w: f32) -> Color {
+        let dir = Vec3::new(
+            self.focal_length,
+            -x_w / self.px_per_unit,
+            -y_w / self.px_per_unit,
+        )
+        .normalize()
+        .rotate(self.transform.rotation);
+
+        world
+            .objects
+            .iter()
+            .find(|object| {
+                let to_object = object.transform.position - self.transform.position;
+                let closest_approach = to_object - dir * to_object.dot(dir);
+                closest_approach.mag() <= object.radius
+            })
+            .map_or(Color::BLACK, |object| object.color)
+    }
+}
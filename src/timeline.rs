@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::world::Transform;
+
+/// One sample of a recorded camera path: when it was captured and what the camera looked like.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Keyframe {
+    pub time: f32,
+    pub transform: Transform,
+    pub focal_length: f32,
+}
+
+/// A recorded camera fly-through: timestamped keyframes that can be replayed at any frame rate,
+/// decoupled from the rate they were recorded at. Serializes to/from RON so a flight can be
+/// saved, shared, and replayed frame-perfectly later.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Timeline {
+    pub keyframes: Vec<Keyframe>,
+}
+
+impl Timeline {
+    /// Parses a `Timeline` previously written by [`Timeline::to_ron`]. Returns `Err` rather than
+    /// panicking, since the RON being parsed may be a hand-edited or otherwise corrupt file found
+    /// on disk at runtime rather than a fixed, compile-time-known asset.
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("failed to serialize Timeline")
+    }
+
+    /// Appends a keyframe. Callers are expected to push in increasing `time` order.
+    pub fn push(&mut self, time: f32, transform: Transform, focal_length: f32) {
+        self.keyframes.push(Keyframe {
+            time,
+            transform,
+            focal_length,
+        });
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |k| k.time)
+    }
+
+    /// Interpolates the camera's transform and focal length at virtual time `t`. Clamps to the
+    /// first/last keyframe outside the recorded range, and returns `None` if nothing was
+    /// recorded.
+    pub fn sample(&self, t: f32) -> Option<(Transform, f32)> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        if t <= first.time {
+            return Some((first.transform, first.focal_length));
+        }
+        if t >= last.time {
+            return Some((last.transform, last.focal_length));
+        }
+
+        let next_index = self.keyframes.partition_point(|k| k.time <= t);
+        let prev = &self.keyframes[next_index - 1];
+        let next = &self.keyframes[next_index];
+
+        let span = next.time - prev.time;
+        let alpha = if span > 0.0 { (t - prev.time) / span } else { 0.0 };
+
+        let transform = Transform {
+            position: prev.transform.position
+                + (next.transform.position - prev.transform.position) * alpha,
+            rotation: prev.transform.rotation.slerp(next.transform.rotation, alpha),
+        };
+        let focal_length = prev.focal_length + (next.focal_length - prev.focal_length) * alpha;
+
+        Some((transform, focal_length))
+    }
+}
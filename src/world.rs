@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Index;
+
+use crate::camera::Camera;
+use crate::math::{Quat, Vec3};
+
+/// Position and orientation of an object or the camera in world space.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Transform {
+    pub position: Vec3,
+    pub rotation: Quat,
+}
+
+/// An 8-bit RGB color.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Self = Self(0, 0, 0);
+
+    /// Averages a set of colors in linear (gamma-decoded) space, returning back to sRGB bytes.
+    pub fn average_linear(colors: impl ExactSizeIterator<Item = Self>) -> Self {
+        let n = colors.len().max(1) as f32;
+        let linear = colors.fold([0.0f32; 3], |mut acc, color| {
+            for i in 0..3 {
+                acc[i] += to_linear(color[i]);
+            }
+            acc
+        });
+        Self(
+            from_linear(linear[0] / n),
+            from_linear(linear[1] / n),
+            from_linear(linear[2] / n),
+        )
+    }
+}
+
+impl Index<usize> for Color {
+    type Output = u8;
+    fn index(&self, i: usize) -> &u8 {
+        match i {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            _ => panic!("Color index out of range: {}", i),
+        }
+    }
+}
+
+fn to_linear(byte: u8) -> f32 {
+    (byte as f32 / 255.0).powf(2.2)
+}
+
+fn from_linear(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0).powf(1.0 / 2.2) * 255.0).round() as u8
+}
+
+/// The scene description loaded from a RON file: everything the camera can render, plus the
+/// camera's starting transform and flight parameters.
+#[derive(Debug, Deserialize)]
+pub struct World {
+    pub camera: Camera,
+    pub objects: Vec<Object>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Object {
+    pub transform: Transform,
+    pub radius: f32,
+    pub color: Color,
+}